@@ -0,0 +1,3 @@
+//! HTTP request handlers.
+
+pub mod api;
@@ -0,0 +1,7 @@
+//! Proxy subsystem: account/token management, request monitoring, and the
+//! HTTP server that forwards provider traffic and exposes the external API.
+
+pub mod handlers;
+pub mod monitor;
+pub mod server;
+pub mod token_manager;
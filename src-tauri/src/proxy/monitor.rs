@@ -0,0 +1,103 @@
+//! Request monitoring: global counters plus a bounded rolling log buffer.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+/// Maximum number of log entries retained in the in-memory buffer.
+const LOG_CAPACITY: usize = 1000;
+
+/// Capacity of the broadcast channel feeding live log subscribers.
+const BROADCAST_CAPACITY: usize = 256;
+
+/// A single forwarded-request log entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub timestamp: i64,
+    pub account_id: String,
+    pub provider: String,
+    pub status: u16,
+    pub success: bool,
+}
+
+/// Snapshot of the global request counters.
+#[derive(Debug, Clone, Serialize)]
+pub struct Stats {
+    pub total_requests: u64,
+    pub success_count: u64,
+    pub error_count: u64,
+}
+
+/// Tracks global counters and a bounded log buffer, broadcasting each new log
+/// entry to any live subscribers (e.g. the SSE stream).
+pub struct Monitor {
+    total_requests: AtomicU64,
+    success_count: AtomicU64,
+    error_count: AtomicU64,
+    logs: Mutex<VecDeque<LogEntry>>,
+    log_tx: broadcast::Sender<LogEntry>,
+}
+
+impl Default for Monitor {
+    fn default() -> Self {
+        let (log_tx, _) = broadcast::channel(BROADCAST_CAPACITY);
+        Self {
+            total_requests: AtomicU64::new(0),
+            success_count: AtomicU64::new(0),
+            error_count: AtomicU64::new(0),
+            logs: Mutex::new(VecDeque::with_capacity(LOG_CAPACITY)),
+            log_tx,
+        }
+    }
+}
+
+impl Monitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a forwarded request, updating counters, appending to the log
+    /// buffer, and notifying any live subscribers.
+    pub fn record(&self, entry: LogEntry) {
+        self.total_requests.fetch_add(1, Ordering::Relaxed);
+        if entry.success {
+            self.success_count.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.error_count.fetch_add(1, Ordering::Relaxed);
+        }
+
+        {
+            let mut logs = self.logs.lock().unwrap();
+            if logs.len() == LOG_CAPACITY {
+                logs.pop_front();
+            }
+            logs.push_back(entry.clone());
+        }
+
+        // A send error just means no subscribers are currently attached.
+        let _ = self.log_tx.send(entry);
+    }
+
+    /// Subscribe to live log entries as they are recorded.
+    pub fn subscribe(&self) -> broadcast::Receiver<LogEntry> {
+        self.log_tx.subscribe()
+    }
+
+    /// Current counter snapshot.
+    pub async fn get_stats(&self) -> Stats {
+        Stats {
+            total_requests: self.total_requests.load(Ordering::Relaxed),
+            success_count: self.success_count.load(Ordering::Relaxed),
+            error_count: self.error_count.load(Ordering::Relaxed),
+        }
+    }
+
+    /// The most recent `limit` log entries, oldest first.
+    pub async fn get_logs(&self, limit: usize) -> Vec<LogEntry> {
+        let logs = self.logs.lock().unwrap();
+        logs.iter().rev().take(limit).rev().cloned().collect()
+    }
+}
@@ -0,0 +1,133 @@
+//! HTTP server wiring: shared application state and router registration for
+//! the external API.
+
+use std::sync::Arc;
+
+use axum::{
+    middleware,
+    routing::{get, post},
+    Router,
+};
+use dashmap::{DashMap, DashSet};
+
+use crate::proxy::handlers::api::{self, AccountUsage, ServerConfig, UpstreamProxyConfig};
+use crate::proxy::monitor::{LogEntry, Monitor};
+use crate::proxy::token_manager::TokenManager;
+
+/// Shared state handed to every API handler. Cloned per request, so every
+/// field is cheap to clone (behind an `Arc`).
+#[derive(Clone)]
+pub struct AppState {
+    pub token_manager: Arc<TokenManager>,
+    pub monitor: Arc<Monitor>,
+    /// Per-account usage accounting, keyed by account id and updated from the
+    /// forward path.
+    pub account_usage: Arc<DashMap<String, AccountUsage>>,
+    /// Bearer-token allow-list. Every `/api` request must present one of these
+    /// keys, so an empty list rejects all `/api` requests.
+    pub api_keys: Arc<DashSet<String>>,
+    /// Optional outbound proxy applied to provider connections.
+    pub upstream_proxy: Option<Arc<UpstreamProxyConfig>>,
+    pub server_config: Arc<ServerConfig>,
+}
+
+impl AppState {
+    /// Build state from startup configuration.
+    pub fn new(
+        token_manager: Arc<TokenManager>,
+        monitor: Arc<Monitor>,
+        server_config: ServerConfig,
+        api_keys: impl IntoIterator<Item = String>,
+        upstream_proxy: Option<UpstreamProxyConfig>,
+    ) -> Self {
+        let keys = DashSet::new();
+        for key in api_keys {
+            keys.insert(key);
+        }
+        Self {
+            token_manager,
+            monitor,
+            account_usage: Arc::new(DashMap::new()),
+            api_keys: Arc::new(keys),
+            upstream_proxy: upstream_proxy.map(Arc::new),
+            server_config: Arc::new(server_config),
+        }
+    }
+
+    /// Build a reqwest client for `provider`, routing through the configured
+    /// upstream proxy (if any) for that provider.
+    pub fn provider_client(&self, provider: &str) -> reqwest::Result<reqwest::Client> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(proxy) = &self.upstream_proxy {
+            builder = proxy.apply(builder, provider)?;
+        }
+        builder.build()
+    }
+}
+
+/// Forward a request for `account_id`/`provider` to the upstream provider,
+/// recording the outcome against both the global monitor and the per-account
+/// usage accounting. Errors building the client or sending the request are
+/// returned without being recorded.
+pub async fn forward(
+    state: &AppState,
+    account_id: &str,
+    provider: &str,
+    url: &str,
+    body: Vec<u8>,
+) -> reqwest::Result<reqwest::Response> {
+    let request_bytes = body.len() as u64;
+    let client = state.provider_client(provider)?;
+    let response = client.post(url).body(body).send().await?;
+    let status = response.status();
+    let success = status.is_success();
+
+    let now = now_unix();
+    state
+        .account_usage
+        .entry(account_id.to_string())
+        .or_default()
+        .record(success, 0, request_bytes, now);
+
+    state.monitor.record(LogEntry {
+        timestamp: now,
+        account_id: account_id.to_string(),
+        provider: provider.to_string(),
+        status: status.as_u16(),
+        success,
+    });
+
+    Ok(response)
+}
+
+/// Build the axum router exposing the external API.
+pub fn build_router(state: AppState) -> Router {
+    let api = Router::new()
+        .route("/accounts", get(api::handle_get_accounts))
+        .route("/accounts/:id/usage", get(api::handle_get_account_usage))
+        .route("/stats", get(api::handle_get_stats))
+        .route("/status", get(api::handle_get_status))
+        .route("/logs", get(api::handle_get_logs))
+        .route("/logs/stream", get(api::handle_logs_stream))
+        .route("/auth/logout", post(api::handle_post_logout))
+        // Bearer-token auth applied uniformly to every /api route above.
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            api::require_api_key,
+        ));
+
+    Router::new()
+        .nest("/api", api)
+        // Unauthenticated so Prometheus can scrape without a bearer token.
+        .route("/metrics", get(api::handle_get_metrics))
+        .with_state(state)
+}
+
+/// Current wall-clock time as a Unix timestamp in seconds.
+pub(crate) fn now_unix() -> i64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
@@ -0,0 +1,56 @@
+//! In-memory registry of the provider accounts the proxy forwards through.
+
+use std::sync::RwLock;
+
+/// A single provider account tracked by the [`TokenManager`].
+#[derive(Debug, Clone)]
+pub struct Account {
+    pub id: String,
+    pub email: String,
+    pub is_rate_limited: bool,
+    pub rate_limit_reset_seconds: Option<u64>,
+}
+
+/// Thread-safe registry of the accounts available for forwarding.
+#[derive(Default)]
+pub struct TokenManager {
+    accounts: RwLock<Vec<Account>>,
+}
+
+impl TokenManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an account with the manager.
+    pub fn insert(&self, account: Account) {
+        self.accounts.write().unwrap().push(account);
+    }
+
+    /// Snapshot of all accounts as `(id, email, is_rate_limited, reset_seconds)`.
+    pub fn list_accounts(&self) -> Vec<(String, String, bool, Option<u64>)> {
+        self.accounts
+            .read()
+            .unwrap()
+            .iter()
+            .map(|a| {
+                (
+                    a.id.clone(),
+                    a.email.clone(),
+                    a.is_rate_limited,
+                    a.rate_limit_reset_seconds,
+                )
+            })
+            .collect()
+    }
+
+    /// Number of accounts currently registered.
+    pub fn len(&self) -> usize {
+        self.accounts.read().unwrap().len()
+    }
+
+    /// Returns `true` when no accounts are registered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}